@@ -104,8 +104,12 @@ let spawn
             await fs.copy(physxWasmSrc, physxWasmDest)
             // start the server or stop here
             if (dev) {
-              // (re)start server
-              spawn?.kill('SIGTERM')
+              // (re)start server, waiting for the old one to save and exit first
+              if (spawn && spawn.exitCode === null && spawn.signalCode === null) {
+                const exited = new Promise(resolve => spawn.once('exit', resolve))
+                spawn.kill('SIGTERM')
+                await exited
+              }
               spawn = fork(path.join(rootDir, 'build/index.js'))
             } else {
               process.exit(1)
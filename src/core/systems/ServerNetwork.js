@@ -30,6 +30,7 @@ export class ServerNetwork extends System {
     this.saveTimerId = null
     this.dirtyBlueprints = new Set()
     this.dirtyApps = new Set()
+    this.shuttingDown = false
     this.isServer = true
     this.queue = []
   }
@@ -111,6 +112,19 @@ export class ServerNetwork extends System {
     return performance.now() / 1000 // seconds
   }
 
+  async shutdown() {
+    this.shuttingDown = true
+    clearTimeout(this.saveTimerId)
+    clearInterval(this.socketIntervalId)
+    // apply anything already received, then disconnect everyone so no more changes come in
+    this.flush()
+    this.sockets.forEach(socket => socket.disconnect())
+    // final save, this one doesn't queue again
+    if (SAVE_INTERVAL) {
+      await this.save()
+    }
+  }
+
   save = async () => {
     const counts = {
       upsertedBlueprints: 0,
@@ -142,15 +156,17 @@ export class ServerNetwork extends System {
       const entity = this.world.entities.get(id)
       if (entity) {
         // it needs creating/updating
-        if (entity.data.uploader || entity.data.mover) {
+        if (entity.data.uploader || (entity.data.mover && !this.shuttingDown)) {
           continue // ignore while uploading or moving
         }
         try {
           const data = cloneDeep(entity.data)
           data.state = null
+          // clear mover so apps still being moved at shutdown are saved where they are (already null otherwise)
+          data.mover = null
           const record = {
             id: entity.data.id,
-            data: JSON.stringify(entity.data),
+            data: JSON.stringify(data),
           }
           await this.db('entities')
             .insert({ ...record, createdAt: now, updatedAt: now })
@@ -177,7 +193,9 @@ export class ServerNetwork extends System {
       )
     }
     // queue again
-    this.saveTimerId = setTimeout(this.save, SAVE_INTERVAL * 1000)
+    if (!this.shuttingDown) {
+      this.saveTimerId = setTimeout(this.save, SAVE_INTERVAL * 1000)
+    }
   }
 
   async onConnection(ws, authToken) {
@@ -179,12 +179,46 @@ async function worldNetwork(fastify) {
 console.log(`running on port ${port}`)
 
 // Graceful shutdown
-process.on('SIGINT', async () => {
-  await fastify.close()
-  process.exit(0)
-})
+const SHUTDOWN_TIMEOUT = parseInt(process.env.SHUTDOWN_TIMEOUT || '10') // seconds
+const CLOSE_TIMEOUT = 3 // seconds
+let shuttingDown = false
+async function shutdown() {
+  if (shuttingDown) {
+    // a second signal forces exit
+    process.exit(1)
+  }
+  shuttingDown = true
+  // don't hang forever if something stalls
+  setTimeout(() => {
+    console.error('shutdown timed out')
+    process.exit(1)
+  }, SHUTDOWN_TIMEOUT * 1000).unref()
+  let code = 0
+  // run each step even if an earlier one fails
+  const step = async fn => {
+    try {
+      await fn()
+    } catch (err) {
+      console.error('shutdown failed')
+      console.error(err)
+      code = 1
+    }
+  }
+  try {
+    // stop accepting connections (this resolves once in-flight requests like uploads finish)
+    const closing = step(() => fastify.close())
+    // disconnect players and do a final world save
+    await step(() => world.network.shutdown())
+    // flush app storage
+    storage.save.cancel()
+    await step(() => storage.persist())
+    // give in-flight requests a moment but don't let them hold up exiting
+    await Promise.race([closing, new Promise(resolve => setTimeout(resolve, CLOSE_TIMEOUT * 1000))])
+    await step(() => db.destroy())
+  } finally {
+    process.exit(code)
+  }
+}
 
-process.on('SIGTERM', async () => {
-  await fastify.close()
-  process.exit(0)
-})
+process.on('SIGINT', shutdown)
+process.on('SIGTERM', shutdown)